@@ -0,0 +1,328 @@
+//! A tiny evaluator for `cfg(...)` expressions, the same syntax Cargo uses
+//! for `#[cfg(...)]` and target specs, but evaluated at runtime against an
+//! arbitrary set of key/value attributes instead of the real compiler
+//! configuration. This lets callers pick a greeting template for a given
+//! target without needing a recompile per platform.
+
+use std::collections::HashMap;
+use std::fmt;
+
+/// The active set of cfg attributes to evaluate against. Presence-only
+/// flags (e.g. `unix`) map to `None`; key/value cfgs (e.g.
+/// `target_os = "linux"`) map to `Some(value)`.
+pub type CfgSet = HashMap<String, Option<String>>;
+
+/// An error produced while tokenizing or parsing a `cfg()` expression.
+/// `position` is the byte offset into the source string where the problem
+/// was found.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub position: usize,
+    pub message: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "cfg expression error at position {}: {}", self.position, self.message)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token {
+    Ident(String),
+    Str(String),
+    Eq,
+    LParen,
+    RParen,
+    Comma,
+}
+
+struct Lexer<'a> {
+    chars: std::iter::Peekable<std::str::CharIndices<'a>>,
+}
+
+impl<'a> Lexer<'a> {
+    fn new(source: &'a str) -> Self {
+        Lexer {
+            chars: source.char_indices().peekable(),
+        }
+    }
+
+    fn error(&self, position: usize, message: impl Into<String>) -> ParseError {
+        ParseError {
+            position,
+            message: message.into(),
+        }
+    }
+
+    fn tokenize(mut self) -> Result<Vec<(usize, Token)>, ParseError> {
+        let mut tokens = Vec::new();
+        while let Some(&(pos, ch)) = self.chars.peek() {
+            match ch {
+                c if c.is_whitespace() => {
+                    self.chars.next();
+                }
+                '(' => {
+                    self.chars.next();
+                    tokens.push((pos, Token::LParen));
+                }
+                ')' => {
+                    self.chars.next();
+                    tokens.push((pos, Token::RParen));
+                }
+                ',' => {
+                    self.chars.next();
+                    tokens.push((pos, Token::Comma));
+                }
+                '=' => {
+                    self.chars.next();
+                    tokens.push((pos, Token::Eq));
+                }
+                '"' => {
+                    tokens.push((pos, Token::Str(self.read_string(pos)?)));
+                }
+                c if c.is_alphabetic() || c == '_' => {
+                    tokens.push((pos, Token::Ident(self.read_ident())));
+                }
+                other => {
+                    return Err(self.error(pos, format!("unexpected character '{other}'")));
+                }
+            }
+        }
+        Ok(tokens)
+    }
+
+    fn read_string(&mut self, start: usize) -> Result<String, ParseError> {
+        self.chars.next(); // consume opening quote
+        let mut value = String::new();
+        loop {
+            match self.chars.next() {
+                Some((_, '"')) => return Ok(value),
+                Some((_, c)) => value.push(c),
+                None => return Err(self.error(start, "unterminated string literal")),
+            }
+        }
+    }
+
+    fn read_ident(&mut self) -> String {
+        let mut ident = String::new();
+        while let Some(&(_, c)) = self.chars.peek() {
+            if c.is_alphanumeric() || c == '_' {
+                ident.push(c);
+                self.chars.next();
+            } else {
+                break;
+            }
+        }
+        ident
+    }
+}
+
+/// A parsed `cfg()` expression, ready to be evaluated against a [`CfgSet`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Expr {
+    /// A bare identifier: true if the attribute is present at all.
+    Present(String),
+    /// `ident = "value"`: true if the attribute is present and equal.
+    Equals(String, String),
+    All(Vec<Expr>),
+    Any(Vec<Expr>),
+    Not(Box<Expr>),
+}
+
+struct Parser {
+    tokens: Vec<(usize, Token)>,
+    pos: usize,
+    end: usize,
+}
+
+impl Parser {
+    fn new(tokens: Vec<(usize, Token)>, end: usize) -> Self {
+        Parser { tokens, pos: 0, end }
+    }
+
+    fn peek(&self) -> Option<&(usize, Token)> {
+        self.tokens.get(self.pos)
+    }
+
+    fn error_at(&self, position: usize, message: impl Into<String>) -> ParseError {
+        ParseError {
+            position,
+            message: message.into(),
+        }
+    }
+
+    fn current_position(&self) -> usize {
+        self.peek().map(|(pos, _)| *pos).unwrap_or(self.end)
+    }
+
+    fn expect(&mut self, expected: &Token) -> Result<(), ParseError> {
+        match self.peek() {
+            Some((_, tok)) if tok == expected => {
+                self.pos += 1;
+                Ok(())
+            }
+            Some((_, tok)) => Err(self.error_at(self.current_position(), format!("expected {expected:?}, found {tok:?}"))),
+            None => Err(self.error_at(self.current_position(), format!("expected {expected:?}, found end of input"))),
+        }
+    }
+
+    fn parse_expr(&mut self) -> Result<Expr, ParseError> {
+        let (pos, name) = match self.peek() {
+            Some((pos, Token::Ident(name))) => (*pos, name.clone()),
+            Some((_, tok)) => {
+                return Err(self.error_at(self.current_position(), format!("expected identifier, found {tok:?}")))
+            }
+            None => return Err(self.error_at(self.current_position(), "expected identifier, found end of input")),
+        };
+        self.pos += 1;
+
+        match self.peek() {
+            Some((_, Token::LParen)) => {
+                self.pos += 1;
+                let args = self.parse_expr_list()?;
+                self.expect(&Token::RParen)?;
+                match name.as_str() {
+                    "all" => Ok(Expr::All(args)),
+                    "any" => Ok(Expr::Any(args)),
+                    "not" => {
+                        let mut args = args;
+                        if args.len() != 1 {
+                            return Err(self.error_at(pos, "not() takes exactly one expression"));
+                        }
+                        Ok(Expr::Not(Box::new(args.remove(0))))
+                    }
+                    other => Err(self.error_at(pos, format!("unknown cfg function '{other}'"))),
+                }
+            }
+            Some((_, Token::Eq)) => {
+                self.pos += 1;
+                match self.peek() {
+                    Some((_, Token::Str(value))) => {
+                        let value = value.clone();
+                        self.pos += 1;
+                        Ok(Expr::Equals(name, value))
+                    }
+                    Some((_, tok)) => Err(self.error_at(
+                        self.current_position(),
+                        format!("expected string literal, found {tok:?}"),
+                    )),
+                    None => Err(self.error_at(self.current_position(), "expected string literal, found end of input")),
+                }
+            }
+            _ => Ok(Expr::Present(name)),
+        }
+    }
+
+    fn parse_expr_list(&mut self) -> Result<Vec<Expr>, ParseError> {
+        let mut exprs = vec![self.parse_expr()?];
+        while let Some((_, Token::Comma)) = self.peek() {
+            self.pos += 1;
+            exprs.push(self.parse_expr()?);
+        }
+        Ok(exprs)
+    }
+
+    fn parse(mut self) -> Result<Expr, ParseError> {
+        let expr = self.parse_expr()?;
+        if let Some((_, tok)) = self.peek() {
+            return Err(self.error_at(self.current_position(), format!("unexpected trailing token {tok:?}")));
+        }
+        Ok(expr)
+    }
+}
+
+impl Expr {
+    fn eval(&self, cfg: &CfgSet) -> bool {
+        match self {
+            Expr::Present(key) => cfg.contains_key(key),
+            Expr::Equals(key, value) => matches!(cfg.get(key), Some(Some(v)) if v == value),
+            Expr::All(exprs) => exprs.iter().all(|e| e.eval(cfg)),
+            Expr::Any(exprs) => exprs.iter().any(|e| e.eval(cfg)),
+            Expr::Not(expr) => !expr.eval(cfg),
+        }
+    }
+}
+
+/// Parses and evaluates `source` (e.g. `cfg(all(target_os = "linux", not(target_arch = "wasm32")))`)
+/// against `cfg_set`, returning `Ok(true)`/`Ok(false)` or a position-bearing
+/// [`ParseError`] for malformed input.
+pub fn evaluate(source: &str, cfg_set: &CfgSet) -> Result<bool, ParseError> {
+    let body = strip_cfg_wrapper(source)?;
+    // `body` is a subslice of `source`; shift error positions so they index `source`.
+    let offset = body.as_ptr() as usize - source.as_ptr() as usize;
+    let shift = |err: ParseError| ParseError {
+        position: err.position + offset,
+        ..err
+    };
+    let tokens = Lexer::new(body).tokenize().map_err(shift)?;
+    let expr = Parser::new(tokens, body.len()).parse().map_err(shift)?;
+    Ok(expr.eval(cfg_set))
+}
+
+/// Strips an optional leading `cfg(` / trailing `)` wrapper, since callers
+/// commonly write the whole `cfg(...)` form rather than just its body.
+fn strip_cfg_wrapper(source: &str) -> Result<&str, ParseError> {
+    let trimmed = source.trim();
+    if let Some(inner) = trimmed.strip_prefix("cfg(") {
+        inner.strip_suffix(')').ok_or(ParseError {
+            position: source.trim_end().len(),
+            message: "unbalanced parens: missing closing ')'".to_string(),
+        })
+    } else {
+        Ok(trimmed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cfg_set(pairs: &[(&str, Option<&str>)]) -> CfgSet {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.map(str::to_string)))
+            .collect()
+    }
+
+    #[test]
+    fn evaluates_presence() {
+        let cfg = cfg_set(&[("unix", None)]);
+        assert_eq!(evaluate("cfg(unix)", &cfg), Ok(true));
+        assert_eq!(evaluate("cfg(windows)", &cfg), Ok(false));
+    }
+
+    #[test]
+    fn evaluates_equality() {
+        let cfg = cfg_set(&[("target_os", Some("linux"))]);
+        assert_eq!(evaluate(r#"cfg(target_os = "linux")"#, &cfg), Ok(true));
+        assert_eq!(evaluate(r#"cfg(target_os = "windows")"#, &cfg), Ok(false));
+    }
+
+    #[test]
+    fn evaluates_all_any_not() {
+        let cfg = cfg_set(&[("target_os", Some("linux")), ("target_arch", Some("x86_64"))]);
+        assert_eq!(
+            evaluate(r#"cfg(all(target_os = "linux", not(target_arch = "wasm32")))"#, &cfg),
+            Ok(true)
+        );
+        assert_eq!(
+            evaluate(r#"cfg(any(target_os = "windows", target_arch = "x86_64"))"#, &cfg),
+            Ok(true)
+        );
+    }
+
+    #[test]
+    fn reports_unbalanced_parens() {
+        let cfg = cfg_set(&[]);
+        assert!(evaluate("cfg(all(unix)", &cfg).is_err());
+    }
+
+    #[test]
+    fn reports_unexpected_token() {
+        let cfg = cfg_set(&[]);
+        assert!(evaluate("cfg(unix, windows)", &cfg).is_err());
+    }
+}
@@ -0,0 +1,96 @@
+//! Loads an explicit override for the default greeting template from, in
+//! order: the `GREETING_TEMPLATE` env var, an optional `greeting.toml` in
+//! the working directory, or nothing (letting callers fall back to the
+//! locale-driven default).
+//!
+//! The result is loaded once, lazily on first use rather than eagerly at
+//! startup since loading it can fail, and cached behind a [`OnceLock`].
+
+use crate::error::GreetError;
+use std::env;
+use std::fs;
+use std::io;
+use std::sync::OnceLock;
+
+const PLACEHOLDER: &str = "{name}";
+
+static EXPLICIT_TEMPLATE: OnceLock<Result<Option<String>, GreetError>> = OnceLock::new();
+
+/// Returns the explicitly configured greeting template, if any.
+///
+/// `Ok(None)` means no override is configured; callers should use their
+/// own built-in default. `Err` means an override was configured but is
+/// unusable.
+pub(crate) fn explicit_default_template() -> Result<Option<String>, GreetError> {
+    EXPLICIT_TEMPLATE
+        .get_or_init(load_explicit_default_template)
+        .clone()
+}
+
+fn load_explicit_default_template() -> Result<Option<String>, GreetError> {
+    if let Ok(template) = env::var("GREETING_TEMPLATE") {
+        return validate_template(template).map(Some);
+    }
+
+    match fs::read_to_string("greeting.toml") {
+        Ok(contents) => parse_greeting_toml(&contents).map(Some),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(GreetError::ConfigUnreadable(err.to_string())),
+    }
+}
+
+fn validate_template(template: String) -> Result<String, GreetError> {
+    if template.contains(PLACEHOLDER) {
+        Ok(template)
+    } else {
+        Err(GreetError::TemplateParse(format!(
+            "template {template:?} is missing the {PLACEHOLDER} placeholder"
+        )))
+    }
+}
+
+/// Extracts the `template = "..."` key from a minimal `greeting.toml`.
+/// We only need one key, so this reads it directly rather than pulling in
+/// a full TOML parser.
+fn parse_greeting_toml(contents: &str) -> Result<String, GreetError> {
+    for line in contents.lines() {
+        let line = line.trim();
+        let Some(rest) = line.strip_prefix("template") else {
+            continue;
+        };
+        let Some(value) = rest.trim_start().strip_prefix('=') else {
+            continue;
+        };
+        let value = value.trim().trim_matches('"');
+        return validate_template(value.to_string());
+    }
+    Err(GreetError::ConfigUnreadable(
+        "greeting.toml is missing a `template` key".to_string(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_template_missing_placeholder() {
+        assert_eq!(
+            validate_template("Hello there!".to_string()),
+            Err(GreetError::TemplateParse(
+                "template \"Hello there!\" is missing the {name} placeholder".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn parses_template_key_from_toml() {
+        let toml = "# a comment\ntemplate = \"Hi, {name}!\"\n";
+        assert_eq!(parse_greeting_toml(toml), Ok("Hi, {name}!".to_string()));
+    }
+
+    #[test]
+    fn errors_when_toml_has_no_template_key() {
+        assert!(parse_greeting_toml("other = 1\n").is_err());
+    }
+}
@@ -0,0 +1,25 @@
+//! Error type shared by the fallible parts of greeting resolution.
+
+use std::fmt;
+
+/// Why a greeting could not be produced from the configured template.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GreetError {
+    /// The template itself was malformed, e.g. missing the `{name}`
+    /// placeholder required to interpolate the greeted name.
+    TemplateParse(String),
+    /// The configured source (currently `greeting.toml`) exists but
+    /// couldn't be read, or has no `template` key.
+    ConfigUnreadable(String),
+}
+
+impl fmt::Display for GreetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GreetError::TemplateParse(msg) => write!(f, "greeting template parse failed: {msg}"),
+            GreetError::ConfigUnreadable(msg) => write!(f, "greeting config unreadable: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for GreetError {}
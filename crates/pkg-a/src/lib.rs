@@ -1,13 +1,113 @@
 use either::Either;
-use once_cell::sync::Lazy;
+use std::sync::{LazyLock, Mutex};
 
-static GREETING: Lazy<String> = Lazy::new(|| "Hello".to_string());
+pub mod cfg_expr;
+mod config;
+mod error;
+mod locale;
 
-pub fn greet(name: &str) -> String {
-    format!("{}, {name}!", *GREETING)
+pub use cfg_expr::CfgSet;
+pub use error::GreetError;
+
+/// Greeting templates registered via [`register_cfg_greeting`], tried in
+/// registration order against the current target's cfg set.
+static CFG_GREETINGS: LazyLock<Mutex<Vec<(String, String)>>> = LazyLock::new(|| Mutex::new(Vec::new()));
+
+/// Registers a `{name}`-templated greeting to use when `cfg_expr` (e.g.
+/// `cfg(all(target_os = "linux", not(target_arch = "wasm32")))`) evaluates
+/// true against [`current_cfg_set`]. The first registered match wins.
+///
+/// This registry is empty unless a host application populates it; `pkg-b`'s
+/// demo binary deliberately leaves it empty so its output stays
+/// deterministic across platforms and CI. See
+/// `test_greet_prefers_registered_cfg_template` below for an end-to-end
+/// example of registering one and having [`greet`] pick it up.
+pub fn register_cfg_greeting(cfg_expr: impl Into<String>, template: impl Into<String>) {
+    CFG_GREETINGS
+        .lock()
+        .unwrap()
+        .push((cfg_expr.into(), template.into()));
 }
 
-pub fn greet_either(name: Either<&str, String>) -> String {
+/// Builds the cfg set describing the currently running target: `target_os`,
+/// `target_arch`, and `target_family` from [`std::env::consts`], plus the
+/// `unix`/`windows` presence flags.
+pub fn current_cfg_set() -> CfgSet {
+    let mut cfg = CfgSet::new();
+    cfg.insert("target_os".to_string(), Some(std::env::consts::OS.to_string()));
+    cfg.insert("target_arch".to_string(), Some(std::env::consts::ARCH.to_string()));
+    cfg.insert(
+        "target_family".to_string(),
+        Some(std::env::consts::FAMILY.to_string()),
+    );
+    if cfg!(unix) {
+        cfg.insert("unix".to_string(), None);
+    }
+    if cfg!(windows) {
+        cfg.insert("windows".to_string(), None);
+    }
+    cfg
+}
+
+/// Returns the first registered cfg-matched greeting template for `name`,
+/// rendered with `{name}` substituted in.
+fn cfg_template_greeting(name: &str) -> Option<String> {
+    let registry = CFG_GREETINGS.lock().unwrap();
+    let cfg = current_cfg_set();
+    registry.iter().find_map(|(expr, template)| match cfg_expr::evaluate(expr, &cfg) {
+        Ok(true) => Some(template.replace("{name}", name)),
+        _ => None,
+    })
+}
+
+/// Greets `name` in the given `locale`, falling back to English when the
+/// locale is unknown or its bundle is missing the message.
+pub fn greet_in(locale: &str, name: &str) -> String {
+    locale::render_greeting(locale, name)
+}
+
+/// The shared resolution logic behind [`greet`] and [`greet_in_or_default`],
+/// parameterized over the locale to fall back to, in priority order:
+/// 1. a registered cfg-matched template (see [`register_cfg_greeting`]);
+/// 2. an explicit template override from `GREETING_TEMPLATE` or
+///    `greeting.toml` (see [`config`]);
+/// 3. `locale`, via [`greet_in`].
+///
+/// Fails only if an explicit override is configured but unusable -- either
+/// the config source couldn't be read, or its template is missing the
+/// `{name}` placeholder.
+fn resolve_greeting(locale: &str, name: &str) -> Result<String, GreetError> {
+    if let Some(templated) = cfg_template_greeting(name) {
+        return Ok(templated);
+    }
+    if let Some(template) = config::explicit_default_template()? {
+        return Ok(template.replace("{name}", name));
+    }
+    Ok(greet_in(locale, name))
+}
+
+/// Greets `name`, resolving the locale from the `LANG`/`LC_MESSAGES`
+/// environment variables. See [`resolve_greeting`] for the full priority
+/// order and failure modes.
+pub fn greet(name: &str) -> Result<String, GreetError> {
+    resolve_greeting(&locale::active_locale(), name)
+}
+
+/// Like [`greet`], but swallows any error back to the locale's own default
+/// rather than failing.
+pub fn greet_or_default(name: &str) -> String {
+    let locale = locale::active_locale();
+    greet_in_or_default(&locale, name)
+}
+
+/// Like [`greet`], but resolves `locale` explicitly instead of reading it
+/// from the environment, and swallows any error back to that locale's
+/// default rather than failing.
+pub fn greet_in_or_default(locale: &str, name: &str) -> String {
+    resolve_greeting(locale, name).unwrap_or_else(|_| greet_in(locale, name))
+}
+
+pub fn greet_either(name: Either<&str, String>) -> Result<String, GreetError> {
     match name {
         Either::Left(s) => greet(s),
         Either::Right(s) => greet(&s),
@@ -20,6 +120,49 @@ mod tests {
 
     #[test]
     fn test_greet() {
-        assert_eq!(greet("world"), "Hello, world!");
+        // Asserted through an explicit locale rather than `greet` directly:
+        // `greet` resolves the real process `LANG`/`LC_MESSAGES`, so a
+        // developer or CI box with a non-English locale set would make a
+        // hard-coded "Hello, ..." expectation flaky.
+        assert_eq!(greet_in("en", "world"), "Hello, world!");
+    }
+
+    // `greet`/`greet_or_default`/`greet_in_or_default` all consult the
+    // process-wide `CFG_GREETINGS` registry, so every assertion that
+    // depends on its state (empty, then populated) lives in this single
+    // test -- splitting them across tests would race against whichever
+    // one `register_cfg_greeting` runs in, since Rust runs tests in the
+    // same process concurrently by default.
+    #[test]
+    fn test_greet_prefers_registered_cfg_template() {
+        // Before anything is registered, resolution falls through to the
+        // locale default.
+        assert_eq!(greet_in_or_default("en", "world"), "Hello, world!");
+
+        // A `not()` of a key that's never in `current_cfg_set()` is always
+        // true, so this always matches and takes priority over locale
+        // resolution from here on -- demonstrating that
+        // `greet`/`greet_or_default` actually consult the registry
+        // `register_cfg_greeting` populates, regardless of the process's
+        // real `LANG`/`LC_MESSAGES`.
+        register_cfg_greeting("cfg(not(pkg_a_test_marker_never_present))", "Yo, {name}!");
+        assert_eq!(greet("world"), Ok("Yo, world!".to_string()));
+        assert_eq!(greet_or_default("world"), "Yo, world!");
+    }
+
+    #[test]
+    fn test_greet_in_french() {
+        assert_eq!(greet_in("fr", "monde"), "Bonjour, monde !");
+    }
+
+    #[test]
+    fn test_greet_in_unknown_locale_falls_back_to_english() {
+        assert_eq!(greet_in("xx", "world"), "Hello, world!");
+    }
+
+    #[test]
+    fn test_current_cfg_set_includes_target_os() {
+        let cfg = current_cfg_set();
+        assert_eq!(cfg.get("target_os"), Some(&Some(std::env::consts::OS.to_string())));
     }
 }
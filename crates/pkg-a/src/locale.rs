@@ -0,0 +1,103 @@
+//! A minimal Fluent-style message catalog used to pick `greet`'s wording at
+//! runtime instead of hard-coding a single English string.
+//!
+//! Each supported locale gets its own `.ftl`-style bundle under `locales/`,
+//! containing `id = value` pairs where `value` may reference named
+//! arguments as `{ $arg }`. This is a small subset of real Fluent syntax --
+//! just enough to let translators reorder words without touching code.
+
+use std::collections::HashMap;
+use std::env;
+use std::sync::LazyLock;
+
+const DEFAULT_LOCALE: &str = "en";
+
+const EN_FTL: &str = include_str!("../locales/en.ftl");
+const FR_FTL: &str = include_str!("../locales/fr.ftl");
+const DE_FTL: &str = include_str!("../locales/de.ftl");
+
+/// A parsed set of `id = value` messages for a single locale.
+struct Bundle {
+    messages: HashMap<&'static str, &'static str>,
+}
+
+impl Bundle {
+    fn parse(source: &'static str) -> Self {
+        let mut messages = HashMap::new();
+        for line in source.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if let Some((id, value)) = line.split_once('=') {
+                messages.insert(id.trim(), value.trim());
+            }
+        }
+        Bundle { messages }
+    }
+
+    /// Renders `msg_id` with `args` substituted for `{ $name }` placeholders.
+    /// Returns `None` if the message id isn't present in this bundle, or if
+    /// the message references an argument that `args` doesn't supply.
+    fn format(&self, msg_id: &str, args: &[(&str, &str)]) -> Option<String> {
+        let mut rendered = (*self.messages.get(msg_id)?).to_string();
+        for (name, value) in args {
+            let placeholder = format!("{{ ${name} }}");
+            rendered = rendered.replace(&placeholder, value);
+        }
+        if rendered.contains("{ $") {
+            return None;
+        }
+        Some(rendered)
+    }
+}
+
+static BUNDLES: LazyLock<HashMap<&'static str, Bundle>> = LazyLock::new(|| {
+    let mut bundles = HashMap::new();
+    bundles.insert("en", Bundle::parse(EN_FTL));
+    bundles.insert("fr", Bundle::parse(FR_FTL));
+    bundles.insert("de", Bundle::parse(DE_FTL));
+    bundles
+});
+
+/// Picks the active locale from `LANG`/`LC_MESSAGES`, falling back to `en`.
+///
+/// POSIX locale values look like `fr_FR.UTF-8`; we only care about the
+/// language subtag, so everything from the first `_`, `.`, or `@` onward is
+/// dropped.
+pub(crate) fn active_locale() -> String {
+    let raw = env::var("LC_MESSAGES")
+        .or_else(|_| env::var("LANG"))
+        .unwrap_or_default();
+
+    let lang = raw
+        .split(['_', '.', '@'])
+        .next()
+        .unwrap_or("")
+        .to_ascii_lowercase();
+
+    if lang.is_empty() {
+        DEFAULT_LOCALE.to_string()
+    } else {
+        lang
+    }
+}
+
+/// Renders the `greeting` message for `locale`, falling back to `en` when
+/// the locale is unknown or the message/argument is missing there too.
+pub(crate) fn render_greeting(locale: &str, name: &str) -> String {
+    let fallback = &BUNDLES[DEFAULT_LOCALE];
+
+    let bundle = BUNDLES.get(locale).unwrap_or(fallback);
+    let greet_word = bundle
+        .format("greeting-word", &[])
+        .or_else(|| fallback.format("greeting-word", &[]))
+        .unwrap_or_else(|| "Hello".to_string());
+
+    let args = [("greet", greet_word.as_str()), ("name", name)];
+
+    bundle
+        .format("greeting", &args)
+        .or_else(|| fallback.format("greeting", &args))
+        .unwrap_or_else(|| format!("Hello, {name}!"))
+}
@@ -1,8 +1,33 @@
-use once_cell::sync::Lazy;
-use pkg_a::greet;
+use pkg_a::greet_or_default;
+use std::env;
+use std::io::{stdout, IsTerminal};
+use std::sync::LazyLock;
 
-static APP_NAME: Lazy<String> = Lazy::new(|| "pkg-b".to_string());
+static APP_NAME: LazyLock<String> = LazyLock::new(|| "pkg-b".to_string());
+
+const BOLD: &str = "\x1b[1m";
+const RESET: &str = "\x1b[0m";
+
+/// Whether `APP_NAME` should be wrapped in an ANSI bold sequence.
+///
+/// Colorizes when stdout is a real terminal, unless `NO_COLOR` is set.
+/// `CLICOLOR_FORCE` overrides both, forcing color even when piped.
+fn should_colorize() -> bool {
+    if env::var_os("CLICOLOR_FORCE").is_some() {
+        return true;
+    }
+    if env::var_os("NO_COLOR").is_some() {
+        return false;
+    }
+    stdout().is_terminal()
+}
 
 fn main() {
-    println!("[{}] {}", *APP_NAME, greet("Nix"));
+    let app_name = if should_colorize() {
+        format!("{BOLD}{}{RESET}", *APP_NAME)
+    } else {
+        APP_NAME.clone()
+    };
+
+    println!("[{app_name}] {}", greet_or_default("Nix"));
 }
@@ -0,0 +1,70 @@
+//! Integration tests that spawn the actual `pkg-b` binary, so the Nix
+//! pipeline can point this at a sandbox-built artifact and verify the
+//! packaged executable end to end rather than just the library function.
+
+use std::path::PathBuf;
+use std::process::Command;
+
+/// Environment variables that influence `pkg-b`'s output and must be
+/// cleared before each run so the host environment can't leak in.
+const CONTROLLED_VARS: &[&str] = &["LANG", "LC_MESSAGES", "NO_COLOR", "CLICOLOR_FORCE", "GREETING_TEMPLATE"];
+
+/// Locates the compiled `pkg-b` binary: `PKG_B_EXE` if set (how the Nix
+/// build points this test at its own output), else the binary Cargo just
+/// built for this test run.
+fn pkg_b_exe() -> PathBuf {
+    std::env::var_os("PKG_B_EXE")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(env!("CARGO_BIN_EXE_pkg-b")))
+}
+
+/// Runs `pkg-b` with exactly the given environment variables set (all
+/// other controlled vars cleared) and returns its captured stdout.
+fn run_with_env(envs: &[(&str, &str)]) -> String {
+    let mut command = Command::new(pkg_b_exe());
+    for var in CONTROLLED_VARS {
+        command.env_remove(var);
+    }
+    for (key, value) in envs {
+        command.env(key, value);
+    }
+
+    let output = command.output().expect("failed to spawn pkg-b");
+    assert!(output.status.success(), "pkg-b exited with {}", output.status);
+    String::from_utf8(output.stdout).expect("pkg-b stdout was not valid UTF-8")
+}
+
+#[test]
+fn default_output() {
+    assert_eq!(run_with_env(&[]), "[pkg-b] Hello, Nix!\n");
+}
+
+#[test]
+fn french_locale() {
+    assert_eq!(run_with_env(&[("LANG", "fr_FR.UTF-8")]), "[pkg-b] Bonjour, Nix !\n");
+}
+
+#[test]
+fn german_locale() {
+    assert_eq!(run_with_env(&[("LANG", "de_DE.UTF-8")]), "[pkg-b] Hallo, Nix!\n");
+}
+
+#[test]
+fn unrecognized_locale_falls_back_to_english() {
+    assert_eq!(run_with_env(&[("LANG", "xx_XX.UTF-8")]), "[pkg-b] Hello, Nix!\n");
+}
+
+#[test]
+fn explicit_greeting_template_overrides_locale() {
+    assert_eq!(
+        run_with_env(&[("GREETING_TEMPLATE", "Yo {name}"), ("LANG", "fr_FR.UTF-8")]),
+        "[pkg-b] Yo Nix\n"
+    );
+}
+
+#[test]
+fn no_color_keeps_output_plain_when_piped() {
+    // Output is captured through a pipe either way, so this also covers the
+    // default (uncolored) case; NO_COLOR just makes the intent explicit.
+    assert_eq!(run_with_env(&[("NO_COLOR", "1")]), "[pkg-b] Hello, Nix!\n");
+}